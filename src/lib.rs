@@ -51,6 +51,8 @@
 //!    mopafy!(Person, boxed);
 //!    // add methods for Arc<Person>
 //!    mopafy!(Person, arc);
+//!    // add methods for Rc<Person>
+//!    mopafy!(Person, rc);
 //!    ```
 //!
 
@@ -63,6 +65,7 @@ extern crate std;
 #[doc(hidden)]
 pub mod __ {
     pub use core::any::TypeId;
+    pub use core::any::type_name;
     // Option and Result are in the prelude, but they might have been overridden in the macro’s
     // scope, so we do it this way to avoid issues. (Result in particular gets overridden fairly
     // often.)
@@ -71,6 +74,15 @@ pub mod __ {
 
     #[cfg(feature = "std")]
     pub use std::sync::Arc;
+
+    #[cfg(feature = "std")]
+    pub use std::rc::Rc;
+
+    #[cfg(feature = "std")]
+    pub use std::collections::HashMap;
+
+    #[cfg(feature = "std")]
+    pub use std::hash::BuildHasherDefault;
 }
 
 /// A type to emulate dynamic typing.
@@ -91,14 +103,78 @@ pub trait Any: core::any::Any {
     /// Gets the `TypeId` of `self`. UNSTABLE; do not depend on it.
     #[doc(hidden)]
     fn __get_type_id(&self) -> __::TypeId;
+
+    /// Returns the type name of the underlying concrete type.
+    ///
+    /// This is meant for diagnostics (e.g. the `Display` impl of [`DowncastError`]), not for
+    /// identifying types at runtime — the exact format is whatever `core::any::type_name`
+    /// produces and isn't guaranteed to be stable across compiler versions.
+    fn type_name(&self) -> &'static str;
 }
 
 impl<T: core::any::Any> Any for T {
     fn __get_type_id(&self) -> __::TypeId {
         __::TypeId::of::<T>()
     }
+
+    fn type_name(&self) -> &'static str {
+        core::any::type_name::<T>()
+    }
+}
+
+/// The error returned by the `*_checked` downcast methods when the container did not hold the
+/// requested type.
+///
+/// Unlike the plain `downcast_box`/`downcast_arc`/`downcast_rc`, which only give you back
+/// `Err(Self)`, this carries the [type name][Any::type_name] that was expected and the one that
+/// was actually found, while still handing back the original container so no data is lost.
+pub struct DowncastError<C> {
+    expected: &'static str,
+    found: &'static str,
+    original: C,
+}
+
+impl<C> DowncastError<C> {
+    /// Implementation detail of the `mopafy!` macro.
+    #[doc(hidden)]
+    pub fn __new(expected: &'static str, found: &'static str, original: C) -> Self {
+        DowncastError { expected, found, original }
+    }
+
+    /// The type name that was requested.
+    pub fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The type name that was actually found.
+    pub fn found(&self) -> &'static str {
+        self.found
+    }
+
+    /// Recovers the original container (e.g. the `Box<Trait>`) that failed to downcast.
+    pub fn into_inner(self) -> C {
+        self.original
+    }
+}
+
+impl<C> core::fmt::Debug for DowncastError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("DowncastError")
+            .field("expected", &self.expected)
+            .field("found", &self.found)
+            .finish()
+    }
 }
 
+impl<C> core::fmt::Display for DowncastError<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "expected `{}`, found `{}`", self.expected, self.found)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C> std::error::Error for DowncastError<C> {}
+
 /// The macro for implementing all the `Any` methods on your own trait.
 ///
 /// # Instructions for use
@@ -150,6 +226,49 @@ impl<T: core::any::Any> Any for T {
 ///    mopafy!(Trait);
 ///    # fn main() { }
 ///    ```
+///
+/// ## Mopafying generic traits
+///
+/// A trait with type parameters, such as `trait Component<Ctx>: mopa::Any { }`, can't be
+/// mopafied with the plain forms above (the generated `impl Component { ... }` would be
+/// malformed — there's no such thing as a bare `Component`). Instead, give `mopafy!` the
+/// trait's generic parameters:
+///
+/// ```rust,ignore
+/// mopafy!(Component<Ctx>);
+/// ```
+///
+/// which expands to `impl<Ctx> Component<Ctx> { ... }` bounded by `Ctx: 'static` (covering both
+/// the `core` and `boxed` forms, just like the plain `mopafy!(Trait)`) — `'static` is the
+/// default bound because it's what every non-generic trait mopafied by this macro already
+/// requires of itself (via `mopa::Any`), so a bare `mopafy!(Component<Ctx>)` stays a drop-in
+/// generic equivalent of `mopafy!(Trait)` without surprising the caller with a missing-bound
+/// error. If the trait needs different or additional bounds, spell them out with a `where`
+/// clause, putting the mode right after the type list and the bounds after that:
+///
+/// ```rust,ignore
+/// mopafy!(Component<Ctx>, core where Ctx: 'static + Send);
+/// mopafy!(Component<Ctx>, boxed where Ctx: 'static + Send);
+/// ```
+///
+/// or, without picking a mode, to cover both `core` and `boxed` at once:
+///
+/// ```rust,ignore
+/// mopafy!(Component<Ctx> where Ctx: 'static + Send);
+/// ```
+///
+/// `arc` and `rc` are accepted as modes in both forms too, exactly like the non-generic macro.
+///
+/// If instead you only ever use one concrete instantiation, e.g. `Component<u32>`, and don't
+/// want the blanket generic impl, prefix the invocation with `concrete`:
+///
+/// ```rust,ignore
+/// mopafy!(concrete Component<u32>);
+/// mopafy!(concrete Component<u32>, arc);
+/// ```
+///
+/// As always, the `T` being downcast to must itself implement the (possibly instantiated)
+/// trait, which — because the trait extends `mopa::Any` — means `T: 'static`.
 #[macro_export]
 macro_rules! mopafy {
     // deprecated
@@ -163,6 +282,359 @@ macro_rules! mopafy {
         mopafy!($trait_, core);
     };
 
+    // --- concrete instantiation of a generic trait, e.g. `mopafy!(concrete Component<u32>)` ---
+
+    (concrete $trait_:ident<$($param:ty),+>) => {
+        mopafy!(concrete $trait_<$($param),+>, core);
+        mopafy!(concrete $trait_<$($param),+>, boxed);
+    };
+
+    (concrete $trait_:ident<$($param:ty),+>, boxed) => {
+        #[allow(dead_code)]
+        impl $trait_<$($param),+> {
+            /// Returns the boxed value if it is of type `T`, or `Err(Self)` if it isn't.
+            #[inline]
+            pub fn downcast_box<T: $trait_<$($param),+>>(self: Box<Self>) -> $crate::__::Result<Box<T>, Box<Self>> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(self.downcast_box_unchecked())
+                    }
+                } else {
+                    $crate::__::Result::Err(self)
+                }
+            }
+
+            /// Returns the boxed value, blindly assuming it to be of type `T`.
+            /// If you are not *absolutely certain* of `T`, you *must not* call this.
+            #[inline]
+            pub unsafe fn downcast_box_unchecked<T: $trait_<$($param),+>>(self: Box<Self>) -> Box<T> {
+                Box::from_raw(Box::into_raw(self) as *mut T)
+            }
+
+            /// Returns the boxed value if it is of type `T`, or a `DowncastError` describing
+            /// the expected and found types (with the original box restored) if it isn't.
+            #[inline]
+            pub fn downcast_box_checked<T: $trait_<$($param),+>>(self: Box<Self>) -> $crate::__::Result<Box<T>, $crate::DowncastError<Box<Self>>> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(self.downcast_box_unchecked())
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*self);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, self))
+                }
+            }
+        }
+    };
+
+    (concrete $trait_:ident<$($param:ty),+>, arc) => {
+        #[allow(dead_code)]
+        impl $trait_<$($param),+> {
+            #[inline]
+            pub fn downcast_arc<T: $trait_<$($param),+>>(this: $crate::__::Arc<Self>) -> $crate::__::Result<$crate::__::Arc<T>, $crate::__::Arc<Self>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_arc_unchecked(this))
+                    }
+                } else {
+                    $crate::__::Result::Err(this)
+                }
+            }
+
+            #[inline]
+            pub unsafe fn downcast_arc_unchecked<T: $trait_<$($param),+>>(this: $crate::__::Arc<Self>) -> $crate::__::Arc<T> {
+                $crate::__::Arc::from_raw($crate::__::Arc::into_raw(this) as *mut T)
+            }
+
+            /// Returns the `Arc` if it is of type `T`, or a `DowncastError` describing the
+            /// expected and found types (with the original `Arc` restored) if it isn't.
+            #[inline]
+            pub fn downcast_arc_checked<T: $trait_<$($param),+>>(this: $crate::__::Arc<Self>) -> $crate::__::Result<$crate::__::Arc<T>, $crate::DowncastError<$crate::__::Arc<Self>>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_arc_unchecked(this))
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*this);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, this))
+                }
+            }
+        }
+    };
+
+    (concrete $trait_:ident<$($param:ty),+>, rc) => {
+        #[allow(dead_code)]
+        impl $trait_<$($param),+> {
+            #[inline]
+            pub fn downcast_rc<T: $trait_<$($param),+>>(this: $crate::__::Rc<Self>) -> $crate::__::Result<$crate::__::Rc<T>, $crate::__::Rc<Self>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_rc_unchecked(this))
+                    }
+                } else {
+                    $crate::__::Result::Err(this)
+                }
+            }
+
+            #[inline]
+            pub unsafe fn downcast_rc_unchecked<T: $trait_<$($param),+>>(this: $crate::__::Rc<Self>) -> $crate::__::Rc<T> {
+                $crate::__::Rc::from_raw($crate::__::Rc::into_raw(this) as *mut T)
+            }
+
+            /// Returns the `Rc` if it is of type `T`, or a `DowncastError` describing the
+            /// expected and found types (with the original `Rc` restored) if it isn't.
+            #[inline]
+            pub fn downcast_rc_checked<T: $trait_<$($param),+>>(this: $crate::__::Rc<Self>) -> $crate::__::Result<$crate::__::Rc<T>, $crate::DowncastError<$crate::__::Rc<Self>>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_rc_unchecked(this))
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*this);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, this))
+                }
+            }
+        }
+    };
+
+    (concrete $trait_:ident<$($param:ty),+>, core) => {
+        #[allow(dead_code)]
+        impl $trait_<$($param),+> {
+            /// Returns true if the boxed type is the same as `T`
+            #[inline]
+            pub fn is<T: $trait_<$($param),+>>(&self) -> bool {
+                $crate::__::TypeId::of::<T>() == $crate::Any::__get_type_id(self)
+            }
+
+            /// Returns some reference to the boxed value if it is of type `T`, or
+            /// `None` if it isn't.
+            #[inline]
+            pub fn downcast_ref<T: $trait_<$($param),+>>(&self) -> $crate::__::Option<&T> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Option::Some(self.downcast_ref_unchecked())
+                    }
+                } else {
+                    $crate::__::Option::None
+                }
+            }
+
+            /// Returns a reference to the boxed value, blindly assuming it to be of type `T`.
+            /// If you are not *absolutely certain* of `T`, you *must not* call this.
+            #[inline]
+            pub unsafe fn downcast_ref_unchecked<T: $trait_<$($param),+>>(&self) -> &T {
+                &*(self as *const Self as *const T)
+            }
+
+            /// Returns some mutable reference to the boxed value if it is of type `T`, or
+            /// `None` if it isn't.
+            #[inline]
+            pub fn downcast_mut<T: $trait_<$($param),+>>(&mut self) -> $crate::__::Option<&mut T> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Option::Some(self.downcast_mut_unchecked())
+                    }
+                } else {
+                    $crate::__::Option::None
+                }
+            }
+
+            /// Returns a mutable reference to the boxed value, blindly assuming it to be of type `T`.
+            /// If you are not *absolutely certain* of `T`, you *must not* call this.
+            #[inline]
+            pub unsafe fn downcast_mut_unchecked<T: $trait_<$($param),+>>(&mut self) -> &mut T {
+                &mut *(self as *mut Self as *mut T)
+            }
+        }
+    };
+
+    // --- generic trait, e.g. `mopafy!(Component<Ctx>)` ---
+
+    ($trait_:ident<$($gen:ident),+> where $($where:tt)+) => {
+        mopafy!($trait_<$($gen),+>, core where $($where)+);
+        mopafy!($trait_<$($gen),+>, boxed where $($where)+);
+    };
+
+    ($trait_:ident<$($gen:ident),+>) => {
+        mopafy!($trait_<$($gen),+>, core);
+        mopafy!($trait_<$($gen),+>, boxed);
+    };
+
+    ($trait_:ident<$($gen:ident),+>, boxed where $($where:tt)+) => {
+        #[allow(dead_code)]
+        impl<$($gen),+> $trait_<$($gen),+> where $($where)+ {
+            /// Returns the boxed value if it is of type `T`, or `Err(Self)` if it isn't.
+            #[inline]
+            pub fn downcast_box<T: $trait_<$($gen),+>>(self: Box<Self>) -> $crate::__::Result<Box<T>, Box<Self>> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(self.downcast_box_unchecked())
+                    }
+                } else {
+                    $crate::__::Result::Err(self)
+                }
+            }
+
+            /// Returns the boxed value, blindly assuming it to be of type `T`.
+            /// If you are not *absolutely certain* of `T`, you *must not* call this.
+            #[inline]
+            pub unsafe fn downcast_box_unchecked<T: $trait_<$($gen),+>>(self: Box<Self>) -> Box<T> {
+                Box::from_raw(Box::into_raw(self) as *mut T)
+            }
+
+            /// Returns the boxed value if it is of type `T`, or a `DowncastError` describing
+            /// the expected and found types (with the original box restored) if it isn't.
+            #[inline]
+            pub fn downcast_box_checked<T: $trait_<$($gen),+>>(self: Box<Self>) -> $crate::__::Result<Box<T>, $crate::DowncastError<Box<Self>>> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(self.downcast_box_unchecked())
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*self);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, self))
+                }
+            }
+        }
+    };
+
+    ($trait_:ident<$($gen:ident),+>, boxed) => {
+        mopafy!($trait_<$($gen),+>, boxed where $($gen: 'static),+);
+    };
+
+    ($trait_:ident<$($gen:ident),+>, arc where $($where:tt)+) => {
+        #[allow(dead_code)]
+        impl<$($gen),+> $trait_<$($gen),+> where $($where)+ {
+            #[inline]
+            pub fn downcast_arc<T: $trait_<$($gen),+>>(this: $crate::__::Arc<Self>) -> $crate::__::Result<$crate::__::Arc<T>, $crate::__::Arc<Self>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_arc_unchecked(this))
+                    }
+                } else {
+                    $crate::__::Result::Err(this)
+                }
+            }
+
+            #[inline]
+            pub unsafe fn downcast_arc_unchecked<T: $trait_<$($gen),+>>(this: $crate::__::Arc<Self>) -> $crate::__::Arc<T> {
+                $crate::__::Arc::from_raw($crate::__::Arc::into_raw(this) as *mut T)
+            }
+
+            /// Returns the `Arc` if it is of type `T`, or a `DowncastError` describing the
+            /// expected and found types (with the original `Arc` restored) if it isn't.
+            #[inline]
+            pub fn downcast_arc_checked<T: $trait_<$($gen),+>>(this: $crate::__::Arc<Self>) -> $crate::__::Result<$crate::__::Arc<T>, $crate::DowncastError<$crate::__::Arc<Self>>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_arc_unchecked(this))
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*this);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, this))
+                }
+            }
+        }
+    };
+
+    ($trait_:ident<$($gen:ident),+>, arc) => {
+        mopafy!($trait_<$($gen),+>, arc where $($gen: 'static),+);
+    };
+
+    ($trait_:ident<$($gen:ident),+>, rc where $($where:tt)+) => {
+        #[allow(dead_code)]
+        impl<$($gen),+> $trait_<$($gen),+> where $($where)+ {
+            #[inline]
+            pub fn downcast_rc<T: $trait_<$($gen),+>>(this: $crate::__::Rc<Self>) -> $crate::__::Result<$crate::__::Rc<T>, $crate::__::Rc<Self>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_rc_unchecked(this))
+                    }
+                } else {
+                    $crate::__::Result::Err(this)
+                }
+            }
+
+            #[inline]
+            pub unsafe fn downcast_rc_unchecked<T: $trait_<$($gen),+>>(this: $crate::__::Rc<Self>) -> $crate::__::Rc<T> {
+                $crate::__::Rc::from_raw($crate::__::Rc::into_raw(this) as *mut T)
+            }
+
+            /// Returns the `Rc` if it is of type `T`, or a `DowncastError` describing the
+            /// expected and found types (with the original `Rc` restored) if it isn't.
+            #[inline]
+            pub fn downcast_rc_checked<T: $trait_<$($gen),+>>(this: $crate::__::Rc<Self>) -> $crate::__::Result<$crate::__::Rc<T>, $crate::DowncastError<$crate::__::Rc<Self>>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(Self::downcast_rc_unchecked(this))
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*this);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, this))
+                }
+            }
+        }
+    };
+
+    ($trait_:ident<$($gen:ident),+>, rc) => {
+        mopafy!($trait_<$($gen),+>, rc where $($gen: 'static),+);
+    };
+
+    ($trait_:ident<$($gen:ident),+>, core where $($where:tt)+) => {
+        #[allow(dead_code)]
+        impl<$($gen),+> $trait_<$($gen),+> where $($where)+ {
+            /// Returns true if the boxed type is the same as `T`
+            #[inline]
+            pub fn is<T: $trait_<$($gen),+>>(&self) -> bool {
+                $crate::__::TypeId::of::<T>() == $crate::Any::__get_type_id(self)
+            }
+
+            /// Returns some reference to the boxed value if it is of type `T`, or
+            /// `None` if it isn't.
+            #[inline]
+            pub fn downcast_ref<T: $trait_<$($gen),+>>(&self) -> $crate::__::Option<&T> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Option::Some(self.downcast_ref_unchecked())
+                    }
+                } else {
+                    $crate::__::Option::None
+                }
+            }
+
+            /// Returns a reference to the boxed value, blindly assuming it to be of type `T`.
+            /// If you are not *absolutely certain* of `T`, you *must not* call this.
+            #[inline]
+            pub unsafe fn downcast_ref_unchecked<T: $trait_<$($gen),+>>(&self) -> &T {
+                &*(self as *const Self as *const T)
+            }
+
+            /// Returns some mutable reference to the boxed value if it is of type `T`, or
+            /// `None` if it isn't.
+            #[inline]
+            pub fn downcast_mut<T: $trait_<$($gen),+>>(&mut self) -> $crate::__::Option<&mut T> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Option::Some(self.downcast_mut_unchecked())
+                    }
+                } else {
+                    $crate::__::Option::None
+                }
+            }
+
+            /// Returns a mutable reference to the boxed value, blindly assuming it to be of type `T`.
+            /// If you are not *absolutely certain* of `T`, you *must not* call this.
+            #[inline]
+            pub unsafe fn downcast_mut_unchecked<T: $trait_<$($gen),+>>(&mut self) -> &mut T {
+                &mut *(self as *mut Self as *mut T)
+            }
+        }
+    };
+
+    ($trait_:ident<$($gen:ident),+>, core) => {
+        mopafy!($trait_<$($gen),+>, core where $($gen: 'static),+);
+    };
+
     // Implement methods for `Box<Any>`
     ($trait_:ident, boxed) => {
         #[allow(dead_code)]
@@ -185,6 +657,20 @@ macro_rules! mopafy {
             pub unsafe fn downcast_box_unchecked<T: $trait_>(self: Box<Self>) -> Box<T> {
                 Box::from_raw(Box::into_raw(self) as *mut T)
             }
+
+            /// Returns the boxed value if it is of type `T`, or a `DowncastError` describing
+            /// the expected and found types (with the original box restored) if it isn't.
+            #[inline]
+            pub fn downcast_box_checked<T: $trait_>(self: Box<Self>) -> $crate::__::Result<Box<T>, $crate::DowncastError<Box<Self>>> {
+                if self.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok(self.downcast_box_unchecked())
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*self);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, self))
+                }
+            }
         }
     };
 
@@ -207,6 +693,56 @@ macro_rules! mopafy {
             pub unsafe fn downcast_arc_unchecked<T: $trait_>(this: $crate::__::Arc<Self>) -> $crate::__::Arc<T> {
                 $crate::__::Arc::from_raw($crate::__::Arc::into_raw(this) as *mut T)
             }
+
+            /// Returns the `Arc` if it is of type `T`, or a `DowncastError` describing the
+            /// expected and found types (with the original `Arc` restored) if it isn't.
+            #[inline]
+            pub fn downcast_arc_checked<T: $trait_>(this: $crate::__::Arc<Self>) -> $crate::__::Result<$crate::__::Arc<T>, $crate::DowncastError<$crate::__::Arc<Self>>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok($trait_::downcast_arc_unchecked(this))
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*this);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, this))
+                }
+            }
+        }
+    };
+
+    // Implement methods for `Rc<Any>`
+    ($trait_:ident, rc) => {
+        #[allow(dead_code)]
+        impl $trait_ {
+            #[inline]
+            pub fn downcast_rc<T: $trait_>(this: $crate::__::Rc<Self>) -> $crate::__::Result<$crate::__::Rc<T>, $crate::__::Rc<Self>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok($trait_::downcast_rc_unchecked(this))
+                    }
+                } else {
+                    $crate::__::Result::Err(this)
+                }
+            }
+
+            #[inline]
+            pub unsafe fn downcast_rc_unchecked<T: $trait_>(this: $crate::__::Rc<Self>) -> $crate::__::Rc<T> {
+                $crate::__::Rc::from_raw($crate::__::Rc::into_raw(this) as *mut T)
+            }
+
+            /// Returns the `Rc` if it is of type `T`, or a `DowncastError` describing the
+            /// expected and found types (with the original `Rc` restored) if it isn't.
+            #[inline]
+            pub fn downcast_rc_checked<T: $trait_>(this: $crate::__::Rc<Self>) -> $crate::__::Result<$crate::__::Rc<T>, $crate::DowncastError<$crate::__::Rc<Self>>> {
+                if this.is::<T>() {
+                    unsafe {
+                        $crate::__::Result::Ok($trait_::downcast_rc_unchecked(this))
+                    }
+                } else {
+                    let found = $crate::Any::type_name(&*this);
+                    $crate::__::Result::Err($crate::DowncastError::__new($crate::__::type_name::<T>(), found, this))
+                }
+            }
         }
     };
 
@@ -263,6 +799,11 @@ macro_rules! mopafy {
     };
 }
 
+/// A `TypeId`-keyed heterogeneous container for mopafied trait objects. See [`mopa_map!`].
+#[cfg(feature = "std")]
+#[macro_use]
+pub mod map;
+
 #[cfg(doc)]
 mod example {
     use std::prelude::v1::*;
@@ -274,6 +815,7 @@ mod example {
     mopafy!(Person, core);
     mopafy!(Person, boxed);
     mopafy!(Person, arc);
+    mopafy!(Person, rc);
 }
 
 #[cfg(test)]
@@ -287,6 +829,7 @@ mod tests {
     mopafy!(Person, core);
     mopafy!(Person, boxed);
     mopafy!(Person, arc);
+    mopafy!(Person, rc);
 
     #[derive(Clone, Debug, PartialEq)]
     struct Benny {
@@ -380,4 +923,125 @@ mod tests {
         assert_eq!(person1.downcast_ref::<Benny>(), Some(&benny));
         assert_eq!(person2.downcast_ref::<Benny>(), Some(&benny));
     }
+
+    #[test]
+    fn test_rc() {
+        use std::rc::Rc;
+
+        let benny = Benny { kilograms_of_food: 13 };
+        let person: Rc<Person> = Rc::new(benny.clone());
+        let person1 = person.clone();
+        let person2 = person.clone();
+        assert!(person.is::<Benny>());
+        assert!(person1.is::<Benny>());
+        assert!(person2.is::<Benny>());
+        assert_eq!(Rc::strong_count(&person), 3);
+        assert_eq!(person.downcast_ref::<Benny>(), Some(&benny));
+        {
+            let b2 = Person::downcast_rc::<Benny>(person).ok().unwrap();
+            assert_eq!(b2.as_ref(), &benny);
+            assert_eq!(Rc::strong_count(&b2), 3);
+        }
+        assert_eq!(Rc::strong_count(&person1), 2);
+        assert_eq!(person1.downcast_ref::<Benny>(), Some(&benny));
+        assert_eq!(person2.downcast_ref::<Benny>(), Some(&benny));
+    }
+
+    #[test]
+    fn test_downcast_checked() {
+        let benny = Benny { kilograms_of_food: 13 };
+        let person: Box<Person> = Box::new(benny.clone());
+
+        assert_eq!(person.downcast_box_checked::<Benny>().map(|x| *x).ok(), Some(benny.clone()));
+
+        let person: Box<Person> = Box::new(benny.clone());
+        let err = person.downcast_box_checked::<Chris>().err().unwrap();
+        assert_eq!(err.expected(), core::any::type_name::<Chris>());
+        assert_eq!(err.found(), core::any::type_name::<Benny>());
+        assert_eq!(format!("{}", err), format!("expected `{}`, found `{}`", core::any::type_name::<Chris>(), core::any::type_name::<Benny>()));
+        assert_eq!(*err.into_inner().downcast_box::<Benny>().ok().unwrap(), benny);
+    }
+
+    trait GenericPerson<Ctx>: super::Any {
+        fn weight(&self) -> i16;
+    }
+
+    mopafy!(GenericPerson<Ctx>);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct GenericBenny(u8);
+
+    impl GenericPerson<u32> for GenericBenny {
+        fn weight(&self) -> i16 {
+            self.0 as i16 + 60
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct GenericChris;
+
+    impl GenericPerson<u32> for GenericChris {
+        fn weight(&self) -> i16 { -5 }
+    }
+
+    #[test]
+    fn test_generic() {
+        let benny = GenericBenny(13);
+        let person: Box<GenericPerson<u32>> = Box::new(benny.clone());
+
+        assert!(person.is::<GenericBenny>());
+        assert_eq!(person.downcast_ref::<GenericBenny>(), Some(&benny));
+        assert!(!person.is::<GenericChris>());
+        assert_eq!(person.downcast_box::<GenericBenny>().map(|x| *x).ok(), Some(benny));
+    }
+
+    trait BoundedThing<Ctx>: super::Any {
+        fn value(&self) -> i32;
+    }
+
+    mopafy!(BoundedThing<Ctx> where Ctx: 'static + Send);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Widget(i32);
+
+    impl BoundedThing<u32> for Widget {
+        fn value(&self) -> i32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_generic_where() {
+        let widget = Widget(42);
+        let thing: Box<BoundedThing<u32>> = Box::new(widget.clone());
+
+        assert!(thing.is::<Widget>());
+        assert_eq!(thing.downcast_ref::<Widget>(), Some(&widget));
+        assert_eq!(thing.downcast_box::<Widget>().map(|x| *x).ok(), Some(widget));
+    }
+
+    trait Sensor<Ctx>: super::Any {
+        fn reading(&self) -> i32;
+    }
+
+    mopafy!(concrete Sensor<u32>);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Thermometer(i32);
+
+    impl Sensor<u32> for Thermometer {
+        fn reading(&self) -> i32 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_concrete() {
+        let thermo = Thermometer(98);
+        let sensor: Box<Sensor<u32>> = Box::new(thermo.clone());
+
+        assert!(sensor.is::<Thermometer>());
+        assert_eq!(sensor.downcast_ref::<Thermometer>(), Some(&thermo));
+        assert_eq!(sensor.downcast_box::<Thermometer>().map(|x| *x).ok(), Some(thermo));
+    }
 }
@@ -0,0 +1,245 @@
+//! A `TypeId`-keyed heterogeneous container for mopafied trait objects.
+//!
+//! There's no way to express a single `MopaMap<Trait: ?Sized>` generic over an arbitrary
+//! trait in today's Rust — a generic type parameter can't be used as a trait bound for
+//! another parameter (you can't write `insert<T: Trait>` when `Trait` is itself just a type
+//! parameter). So, just like `mopafy!` itself, [`mopa_map!`] is a macro: give it a mopafied
+//! trait and it expands to a small struct specific to that trait.
+
+use core::hash::Hasher;
+
+/// A [`Hasher`] specialized for `TypeId` keys.
+///
+/// `core::any::TypeId` already hashes to a well-distributed 64-bit value, so running it
+/// through SipHash (the default `HashMap` hasher) just wastes cycles. This hasher instead
+/// returns that value unchanged, which makes missing-key lookups roughly an order of magnitude
+/// faster than the default.
+///
+/// # Invariant
+///
+/// This is only sound when every key hashed through it is a `TypeId` — or, more precisely,
+/// anything whose `Hash` impl calls `Hasher::write_u64` exactly once and nothing else. Anything
+/// that reaches `Hasher::write` panics in debug builds and silently produces a low-quality hash
+/// in release builds (it folds the bytes rather than losing them outright, so it stays a valid,
+/// if no-longer-free, hasher). Repeated `write_u64` calls are not caught in either build: each
+/// one replaces the stored value, so only the last is hashed.
+#[derive(Default)]
+pub struct TypeIdHasher {
+    hash: u64,
+}
+
+impl Hasher for TypeIdHasher {
+    #[inline]
+    fn write(&mut self, bytes: &[u8]) {
+        debug_assert!(
+            false,
+            "TypeIdHasher is only sound for TypeId keys, which hash via a single write_u64 call"
+        );
+        for &byte in bytes {
+            self.hash = self.hash.rotate_left(8) ^ u64::from(byte);
+        }
+    }
+
+    #[inline]
+    fn write_u64(&mut self, n: u64) {
+        self.hash = n;
+    }
+
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.hash
+    }
+}
+
+/// Generates a `TypeId`-keyed heterogeneous container for a mopafied trait.
+///
+/// `mopa_map!($name, $trait_)` defines `$name`, a struct that stores at most one boxed
+/// `$trait_` per concrete implementing type and looks it back up by `TypeId`. It's built on
+/// the macro-generated `downcast_box`/`downcast_ref`/`downcast_mut` methods, so `$trait_` must
+/// already have been mopafied with (at least) `core` and `boxed`, e.g. via plain
+/// `mopafy!($trait_)`.
+///
+/// ```rust,ignore
+/// trait Component: mopa::Any { }
+/// mopafy!(Component);
+/// mopa_map!(ComponentMap, Component);
+///
+/// let mut components = ComponentMap::new();
+/// components.insert(Physics::default());
+/// let physics: Option<&Physics> = components.get::<Physics>();
+/// ```
+#[macro_export]
+macro_rules! mopa_map {
+    ($name:ident, $trait_:ident) => {
+        #[allow(dead_code)]
+        pub struct $name {
+            inner: $crate::__::HashMap<
+                $crate::__::TypeId,
+                Box<$trait_>,
+                $crate::__::BuildHasherDefault<$crate::map::TypeIdHasher>,
+            >,
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Creates an empty map.
+            #[inline]
+            pub fn new() -> Self {
+                $name { inner: $crate::__::HashMap::default() }
+            }
+
+            /// Returns the number of stored values.
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.inner.len()
+            }
+
+            /// Returns true if the map holds no values.
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.inner.is_empty()
+            }
+
+            /// Returns true if a value of type `T` is stored.
+            #[inline]
+            pub fn contains<T: $trait_>(&self) -> bool {
+                self.inner.contains_key(&$crate::__::TypeId::of::<T>())
+            }
+
+            /// Inserts `value`, returning the previously stored value of the same concrete
+            /// type, if any.
+            #[inline]
+            pub fn insert<T: $trait_>(&mut self, value: T) -> $crate::__::Option<T> {
+                self.inner
+                    .insert($crate::__::TypeId::of::<T>(), Box::new(value))
+                    // Safe: the map only ever stores a `Box<T>` under the key `TypeId::of::<T>()`,
+                    // so whatever was previously there under this same key must be a `T` too.
+                    .map(|old| unsafe { *old.downcast_box_unchecked() })
+            }
+
+            /// Returns a reference to the stored value of type `T`, if any.
+            #[inline]
+            pub fn get<T: $trait_>(&self) -> $crate::__::Option<&T> {
+                self.inner
+                    .get(&$crate::__::TypeId::of::<T>())
+                    .and_then(|v| v.downcast_ref::<T>())
+            }
+
+            /// Returns a mutable reference to the stored value of type `T`, if any.
+            #[inline]
+            pub fn get_mut<T: $trait_>(&mut self) -> $crate::__::Option<&mut T> {
+                self.inner
+                    .get_mut(&$crate::__::TypeId::of::<T>())
+                    .and_then(|v| v.downcast_mut::<T>())
+            }
+
+            /// Removes and returns the stored value of type `T`, if any.
+            #[inline]
+            pub fn remove<T: $trait_>(&mut self) -> $crate::__::Option<T> {
+                self.inner
+                    .remove(&$crate::__::TypeId::of::<T>())
+                    // Safe: see `insert`.
+                    .map(|v| unsafe { *v.downcast_box_unchecked() })
+            }
+
+            /// Returns a reference to the stored value of type `T`, inserting `default` first
+            /// if absent.
+            #[inline]
+            pub fn get_or_insert<T: $trait_>(&mut self, default: T) -> &mut T {
+                self.get_or_insert_with(|| default)
+            }
+
+            /// Returns a reference to the stored value of type `T`, computing a default with
+            /// `f` first if absent.
+            #[inline]
+            pub fn get_or_insert_with<T: $trait_, F: FnOnce() -> T>(&mut self, f: F) -> &mut T {
+                self.inner
+                    .entry($crate::__::TypeId::of::<T>())
+                    .or_insert_with(|| Box::new(f()))
+                    .downcast_mut::<T>()
+                    .unwrap()
+            }
+        }
+
+        impl Default for $name {
+            #[inline]
+            fn default() -> Self {
+                $name::new()
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::prelude::v1::*;
+
+    pub trait Component: crate::Any {
+        fn describe(&self) -> &'static str;
+    }
+
+    mopafy!(Component);
+    mopa_map!(ComponentMap, Component);
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Physics {
+        mass: u32,
+    }
+
+    impl Component for Physics {
+        fn describe(&self) -> &'static str {
+            "physics"
+        }
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Render;
+
+    impl Component for Render {
+        fn describe(&self) -> &'static str {
+            "render"
+        }
+    }
+
+    #[test]
+    fn test_insert_get_remove() {
+        let mut map = ComponentMap::new();
+        assert!(map.is_empty());
+
+        assert_eq!(map.insert(Physics { mass: 10 }), None);
+        assert_eq!(map.len(), 1);
+        assert!(map.contains::<Physics>());
+        assert!(!map.contains::<Render>());
+        assert_eq!(map.get::<Physics>(), Some(&Physics { mass: 10 }));
+        assert_eq!(map.get::<Render>(), None);
+
+        map.get_mut::<Physics>().unwrap().mass = 20;
+        assert_eq!(map.get::<Physics>(), Some(&Physics { mass: 20 }));
+
+        let old = map.insert(Physics { mass: 99 });
+        assert_eq!(old, Some(Physics { mass: 20 }));
+
+        assert_eq!(map.insert(Render), None);
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.remove::<Render>(), Some(Render));
+        assert_eq!(map.remove::<Render>(), None);
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn test_get_or_insert() {
+        let mut map = ComponentMap::new();
+
+        let render = map.get_or_insert_with(|| Render);
+        assert_eq!(render.describe(), "render");
+        assert_eq!(map.len(), 1);
+
+        let physics = map.get_or_insert(Physics { mass: 1000 });
+        assert_eq!(physics.mass, 1000);
+
+        // Already present, so the default passed in here is discarded.
+        let physics = map.get_or_insert(Physics { mass: 2000 });
+        assert_eq!(physics.mass, 1000);
+    }
+}